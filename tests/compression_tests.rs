@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod tests {
-    use bunko::{compress, decompress_to_string, CompressionFormat, CompressionLevel};
+    use bunko::{
+        compress, compress_parallel, compress_reader, compress_tagged, compress_writer,
+        decompress, decompress_checked, decompress_parallel, decompress_reader,
+        decompress_tagged, decompress_to_string, decompress_writer, validate, BlockCompressor,
+        BlockDecompressor, CompressionFormat, CompressionLevel, Flush,
+    };
 
     #[test]
     fn main() {
@@ -11,4 +16,195 @@ mod tests {
         let decompressed = decompress_to_string(&compressed).expect("Decompression failed");
         assert_eq!(input, decompressed, "Decompressed data should match the input");
     }
+
+    #[test]
+    fn codec_roundtrip_flate2_formats() {
+        let input = b"the quick brown fox jumps over the lazy dog".repeat(8);
+        for format in [CompressionFormat::Gzip, CompressionFormat::Deflate, CompressionFormat::Zlib] {
+            let compressed = compress(&input, format, CompressionLevel::Default).expect("Compression failed");
+            let decompressed = decompress(&compressed, format).expect("Decompression failed");
+            assert_eq!(decompressed, input);
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_roundtrip() {
+        let input = b"the quick brown fox jumps over the lazy dog".repeat(8);
+        let compressed = compress(&input, CompressionFormat::Zstd, CompressionLevel::Best).expect("Compression failed");
+        let decompressed = decompress(&compressed, CompressionFormat::Zstd).expect("Decompression failed");
+        assert_eq!(decompressed, input);
+    }
+
+    #[cfg(feature = "brotli")]
+    #[test]
+    fn brotli_roundtrip() {
+        let input = b"the quick brown fox jumps over the lazy dog".repeat(8);
+        let compressed = compress(&input, CompressionFormat::Brotli, CompressionLevel::Best).expect("Compression failed");
+        let decompressed = decompress(&compressed, CompressionFormat::Brotli).expect("Decompression failed");
+        assert_eq!(decompressed, input);
+    }
+
+    #[cfg(feature = "bzip2")]
+    #[test]
+    fn bzip2_roundtrip() {
+        let input = b"the quick brown fox jumps over the lazy dog".repeat(8);
+        let compressed = compress(&input, CompressionFormat::Bzip2, CompressionLevel::Best).expect("Compression failed");
+        let decompressed = decompress(&compressed, CompressionFormat::Bzip2).expect("Decompression failed");
+        assert_eq!(decompressed, input);
+    }
+
+    #[test]
+    fn tagged_roundtrip() {
+        let input = b"self-describing container round trip".repeat(4);
+        let tagged = compress_tagged(&input, CompressionFormat::Zlib, CompressionLevel::Best)
+            .expect("compress_tagged failed");
+
+        // `decompress_tagged` recovers the format from the header alone.
+        let decompressed = decompress_tagged(&tagged).expect("decompress_tagged failed");
+        assert_eq!(decompressed, input);
+    }
+
+    #[test]
+    fn tagged_rejects_unrecognized_magic() {
+        let err = decompress_tagged(&[0, 1, 2, 3, 4, 5, 6, 7]).unwrap_err();
+        assert!(matches!(err, bunko::BunkoError::DeserializationError(_)));
+    }
+
+    #[test]
+    fn reader_roundtrip() {
+        use std::io::{Cursor, Read};
+
+        let input = b"streaming through a reader adaptor".repeat(16);
+        let mut reader = compress_reader(Cursor::new(input.clone()), CompressionFormat::Gzip, CompressionLevel::Default)
+            .expect("compress_reader failed");
+        let mut compressed = Vec::new();
+        reader.read_to_end(&mut compressed).expect("reading compressed bytes failed");
+
+        let mut decoder = decompress_reader(Cursor::new(compressed), CompressionFormat::Gzip)
+            .expect("decompress_reader failed");
+        let mut decompressed = Vec::new();
+        decoder.read_to_end(&mut decompressed).expect("reading decompressed bytes failed");
+
+        assert_eq!(decompressed, input);
+    }
+
+    #[test]
+    fn writer_roundtrip() {
+        use std::io::Write;
+
+        let input = b"streaming through a writer adaptor".repeat(16);
+        let mut writer = compress_writer(Vec::new(), CompressionFormat::Zlib, CompressionLevel::Default)
+            .expect("compress_writer failed");
+        writer.write_all(&input).expect("writing to compress writer failed");
+        let compressed = writer.finish().expect("finishing compress writer failed");
+
+        let mut decoder = decompress_writer(Vec::new(), CompressionFormat::Zlib)
+            .expect("decompress_writer failed");
+        decoder.write_all(&compressed).expect("writing to decompress writer failed");
+        let decompressed = decoder.finish().expect("finishing decompress writer failed");
+
+        assert_eq!(decompressed, input);
+    }
+
+    #[test]
+    fn block_compressor_sync_flush_roundtrip() {
+        let mut compressor = BlockCompressor::new(CompressionLevel::Default, false);
+        let mut decompressor = BlockDecompressor::new(false);
+
+        let mut compressed = Vec::new();
+        for message in [&b"first message"[..], &b"second message"[..]] {
+            let mut out = vec![0u8; 4096];
+            let progress = compressor
+                .compress(message, &mut out, Flush::Sync)
+                .expect("compress failed");
+            assert_eq!(progress.bytes_read, message.len());
+            compressed.extend_from_slice(&out[..progress.bytes_written]);
+        }
+        let mut out = vec![0u8; 64];
+        let progress = compressor
+            .compress(&[], &mut out, Flush::Finish)
+            .expect("compress finish failed");
+        compressed.extend_from_slice(&out[..progress.bytes_written]);
+
+        let mut out = vec![0u8; 4096];
+        let progress = decompressor
+            .decompress(&compressed, &mut out, Flush::Finish)
+            .expect("decompress failed");
+
+        assert_eq!(&out[..progress.bytes_written], b"first messagesecond message".as_slice());
+    }
+
+    #[test]
+    fn parallel_roundtrip() {
+        let input = b"parallel block compression across several chunks of input data".repeat(64);
+        let compressed = compress_parallel(&input, CompressionFormat::Gzip, CompressionLevel::Default, 256)
+            .expect("compress_parallel failed");
+        let decompressed = decompress_parallel(&compressed, CompressionFormat::Gzip)
+            .expect("decompress_parallel failed");
+        assert_eq!(decompressed, input);
+    }
+
+    #[test]
+    fn parallel_rejects_truncated_block_index() {
+        // A block count that claims more per-block length entries than the
+        // buffer actually has must error, not overflow/panic.
+        let mut input = (3u64).to_le_bytes().to_vec();
+        input.extend_from_slice(&1u64.to_le_bytes());
+        let err = decompress_parallel(&input, CompressionFormat::Gzip).unwrap_err();
+        assert!(matches!(err, bunko::BunkoError::DecompressionError(_)));
+    }
+
+    #[test]
+    fn parallel_rejects_implausible_block_count() {
+        // A block count far larger than the buffer could ever hold must
+        // error, not panic trying to allocate `Vec::with_capacity(block_count)`.
+        let input = u64::MAX.to_le_bytes().to_vec();
+        let err = decompress_parallel(&input, CompressionFormat::Gzip).unwrap_err();
+        assert!(matches!(err, bunko::BunkoError::DecompressionError(_)));
+    }
+
+    #[test]
+    fn validate_passes_on_intact_streams() {
+        let input = b"validate me".repeat(4);
+        for format in [CompressionFormat::Zlib, CompressionFormat::Gzip] {
+            let compressed = compress(&input, format, CompressionLevel::Default).expect("Compression failed");
+            validate(&compressed, format).expect("validate should accept an intact stream");
+            assert_eq!(decompress_checked(&compressed, format).expect("decompress_checked failed"), input);
+        }
+    }
+
+    #[test]
+    fn validate_detects_corruption() {
+        let input = b"validate me".repeat(4);
+        for format in [CompressionFormat::Zlib, CompressionFormat::Gzip] {
+            let mut compressed = compress(&input, format, CompressionLevel::Default).expect("Compression failed");
+            let last = compressed.len() - 1;
+            compressed[last] ^= 0xFF;
+
+            let err = validate(&compressed, format).expect_err("corrupted stream should fail validation");
+            assert!(matches!(err, bunko::BunkoError::ChecksumMismatch(_)));
+        }
+    }
+
+    #[test]
+    fn decompress_reports_a_readable_error_message() {
+        // `decompress` should surface a hand-written sentence, not the
+        // `BunkoError` variant's debug repr.
+        let err = decompress(b"not a valid gzip stream", CompressionFormat::Gzip).unwrap_err();
+        assert!(err.starts_with("Decompression error: "));
+        assert!(!err.contains("DecompressionError("));
+    }
+
+    #[test]
+    fn unsupported_codec_errors_instead_of_panicking() {
+        // Without the corresponding cargo feature enabled, Zstd/Brotli/Bzip2
+        // still dispatch through `codec()` but should report a normal error.
+        #[cfg(not(feature = "zstd"))]
+        assert!(compress(b"data", CompressionFormat::Zstd, CompressionLevel::Default).is_err());
+        #[cfg(not(feature = "brotli"))]
+        assert!(compress(b"data", CompressionFormat::Brotli, CompressionLevel::Default).is_err());
+        #[cfg(not(feature = "bzip2"))]
+        assert!(compress(b"data", CompressionFormat::Bzip2, CompressionLevel::Default).is_err());
+    }
 }